@@ -74,7 +74,17 @@ pub enum HistoryPersistence {
 
 /// Collection of settings that are specific to the TUI.
 #[derive(Deserialize, Debug, Clone, PartialEq, Default)]
-pub struct Tui {}
+pub struct Tui {
+    /// Built-in slash commands (without the leading `/`) that should be
+    /// hidden from the popup and refused if invoked.
+    #[serde(default)]
+    pub disabled_slash_commands: Vec<String>,
+
+    /// Replacement descriptions for built-in slash commands, keyed by command
+    /// name (without the leading `/`), e.g. to localize the popup.
+    #[serde(default)]
+    pub slash_command_descriptions: HashMap<String, String>,
+}
 
 #[derive(Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct SandboxWorkspaceWrite {
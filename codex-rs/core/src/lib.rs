@@ -44,9 +44,10 @@ mod openai_model_info;
 mod openai_tools;
 pub mod plan_tool;
 mod project_doc;
-mod rollout;
+pub mod rollout;
 pub(crate) mod safety;
 pub mod seatbelt;
+pub mod session_archive;
 pub mod shell;
 pub mod spawn;
 pub mod turn_diff_tracker;
@@ -2,8 +2,13 @@
 
 use std::fs::File;
 use std::fs::{self};
+use std::io::BufRead;
+use std::io::BufReader;
 use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::io::Write;
 use std::path::Path;
+use std::path::PathBuf;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -19,18 +24,24 @@ use tracing::info;
 use tracing::warn;
 use uuid::Uuid;
 
+use crate::client_common::USER_INSTRUCTIONS_START;
 use crate::config::Config;
+use crate::environment_context::ENVIRONMENT_CONTEXT_START;
 use crate::git_info::GitInfo;
 use crate::git_info::collect_git_info;
+use crate::models::ContentItem;
 use crate::models::ResponseItem;
 
-const SESSIONS_SUBDIR: &str = "sessions";
+pub(crate) const SESSIONS_SUBDIR: &str = "sessions";
 
-#[derive(Serialize, Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
 pub struct SessionMeta {
     pub id: Uuid,
     pub timestamp: String,
     pub instructions: Option<String>,
+    /// Set when this session was forked from another one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_session_id: Option<Uuid>,
 }
 
 #[derive(Serialize)]
@@ -41,7 +52,7 @@ struct SessionMetaWithGit {
     git: Option<GitInfo>,
 }
 
-#[derive(Serialize, Deserialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
 pub struct SessionStateSnapshot {}
 
 #[derive(Serialize, Deserialize, Default, Clone)]
@@ -54,6 +65,174 @@ pub struct SavedSession {
     pub session_id: Uuid,
 }
 
+/// A single line of a rollout file, classified by what it records.
+#[derive(Clone, Debug)]
+pub enum SessionRecord {
+    /// The [`SessionMeta`] header on the first line.
+    Meta(SessionMeta),
+    Item(ResponseItem),
+    State(SessionStateSnapshot),
+}
+
+/// Lazily stream the records of the rollout at `path` line by line, without
+/// reading the whole file into memory.
+///
+/// Blank lines are skipped and invalid UTF-8 is decoded lossily. A line that
+/// cannot be read or parsed is yielded as an `Err` item so callers can decide
+/// whether to keep going.
+pub fn session_records(
+    path: &Path,
+) -> std::io::Result<impl Iterator<Item = std::io::Result<SessionRecord>>> {
+    let reader = BufReader::new(File::open(path)?);
+    Ok(reader
+        .split(b'\n')
+        .enumerate()
+        .filter_map(|(idx, line)| match line {
+            Ok(bytes) => {
+                let line = String::from_utf8_lossy(&bytes);
+                if line.trim().is_empty() {
+                    None
+                } else {
+                    parse_session_record(idx, &line).transpose()
+                }
+            }
+            Err(e) => Some(Err(e)),
+        }))
+}
+
+/// Parse the zero-based line `idx` of a rollout. Returns `Ok(None)` for items
+/// that are never persisted, mirroring [`RolloutRecorder::resume`].
+fn parse_session_record(idx: usize, line: &str) -> std::io::Result<Option<SessionRecord>> {
+    let invalid = |e: serde_json::Error| {
+        IoError::new(ErrorKind::InvalidData, format!("line {}: {e}", idx + 1))
+    };
+    if idx == 0 {
+        let meta = serde_json::from_str::<SessionMeta>(line).map_err(invalid)?;
+        return Ok(Some(SessionRecord::Meta(meta)));
+    }
+
+    let v: Value = serde_json::from_str(line).map_err(invalid)?;
+    if v.get("record_type").and_then(|rt| rt.as_str()) == Some("state") {
+        let state = serde_json::from_value::<SessionStateSnapshot>(v).map_err(invalid)?;
+        return Ok(Some(SessionRecord::State(state)));
+    }
+    match serde_json::from_value::<ResponseItem>(v).map_err(invalid)? {
+        ResponseItem::Other => Ok(None),
+        item => Ok(Some(SessionRecord::Item(item))),
+    }
+}
+
+/// Reduce the rollout at `path` to a deterministic, extractive summary of at
+/// most `max_chars` characters, suitable for seeding a new conversation.
+///
+/// Each user and assistant message becomes one `role: text` line, in order,
+/// with whitespace collapsed and every line truncated to an equal share of the
+/// budget. Injected context messages and unparseable lines are skipped.
+pub fn summarize_session(path: &Path, max_chars: usize) -> std::io::Result<String> {
+    let mut turns = Vec::new();
+    for record in session_records(path)? {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) if e.kind() == ErrorKind::InvalidData => continue,
+            Err(e) => return Err(e),
+        };
+        let SessionRecord::Item(ResponseItem::Message { role, content, .. }) = record else {
+            continue;
+        };
+        if role != "user" && role != "assistant" {
+            continue;
+        }
+        let text = content
+            .iter()
+            .filter_map(|c| match c {
+                ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                    Some(text.as_str())
+                }
+                ContentItem::InputImage { .. } => None,
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        if text.starts_with(USER_INSTRUCTIONS_START) || text.starts_with(ENVIRONMENT_CONTEXT_START)
+        {
+            continue;
+        }
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !text.is_empty() {
+            turns.push(format!("{role}: {text}"));
+        }
+    }
+    if turns.is_empty() {
+        return Ok(String::new());
+    }
+
+    // Leave room for the newlines that separate the turns.
+    let per_turn = max_chars.saturating_sub(turns.len() - 1) / turns.len();
+    let lines: Vec<String> = turns
+        .iter()
+        .map(|turn| truncate_chars(turn, per_turn))
+        .filter(|line| !line.is_empty())
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+/// Truncate `s` to at most `max_chars` characters, marking any cut with `…`.
+fn truncate_chars(s: &str, max_chars: usize) -> String {
+    if s.chars().count() <= max_chars {
+        return s.to_string();
+    }
+    match max_chars {
+        0 => String::new(),
+        n => s.chars().take(n - 1).chain(std::iter::once('…')).collect(),
+    }
+}
+
+/// Result of [`verify_session`]. Blank lines are not counted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifyReport {
+    pub valid_lines: usize,
+    pub invalid_lines: usize,
+    /// One-based line number of the first invalid line, if any.
+    pub first_invalid_line: Option<usize>,
+}
+
+impl VerifyReport {
+    pub fn is_clean(&self) -> bool {
+        self.invalid_lines == 0
+    }
+}
+
+/// Check the structural integrity of the rollout at `path`, e.g. to diagnose
+/// corruption after syncing sessions between machines.
+///
+/// The first line must parse as [`SessionMeta`]. Every later non-empty line
+/// must be a JSON object tagged with either `type` (a [`ResponseItem`]) or
+/// `record_type`; an untagged object is a misplaced metadata header.
+pub fn verify_session(path: &Path) -> std::io::Result<VerifyReport> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut report = VerifyReport::default();
+    for (idx, line) in reader.split(b'\n').enumerate() {
+        let bytes = line?;
+        let line = String::from_utf8_lossy(&bytes);
+        if line.trim().is_empty() {
+            continue;
+        }
+        let valid = if idx == 0 {
+            serde_json::from_str::<SessionMeta>(&line).is_ok()
+        } else {
+            serde_json::from_str::<Value>(&line).is_ok_and(|v| {
+                v.is_object() && (v.get("type").is_some() || v.get("record_type").is_some())
+            })
+        };
+        if valid {
+            report.valid_lines += 1;
+        } else {
+            report.invalid_lines += 1;
+            report.first_invalid_line.get_or_insert(idx + 1);
+        }
+    }
+    Ok(report)
+}
+
 /// Records all [`ResponseItem`]s for a session and flushes them to disk after
 /// every update.
 ///
@@ -85,16 +264,12 @@ impl RolloutRecorder {
     ) -> std::io::Result<Self> {
         let LogFileInfo {
             file,
+            path: _,
             session_id,
             timestamp,
         } = create_log_file(config, uuid)?;
 
-        let timestamp_format: &[FormatItem] = format_description!(
-            "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3]Z"
-        );
-        let timestamp = timestamp
-            .format(timestamp_format)
-            .map_err(|e| IoError::other(format!("failed to format timestamp: {e}")))?;
+        let timestamp = format_meta_timestamp(timestamp)?;
 
         // Clone the cwd for the spawned task to collect git info asynchronously
         let cwd = config.cwd.clone();
@@ -114,6 +289,7 @@ impl RolloutRecorder {
                 timestamp,
                 id: session_id,
                 instructions,
+                parent_session_id: None,
             }),
             cwd,
         ));
@@ -160,7 +336,10 @@ impl RolloutRecorder {
         cwd: std::path::PathBuf,
     ) -> std::io::Result<(Self, SavedSession)> {
         info!("Resuming rollout from {path:?}");
-        let text = tokio::fs::read_to_string(path).await?;
+        // Decode lossily so a single corrupted byte does not make the whole
+        // session unrecoverable.
+        let bytes = tokio::fs::read(path).await?;
+        let text = String::from_utf8_lossy(&bytes);
         let mut lines = text.lines();
         let meta_line = lines
             .next()
@@ -246,6 +425,9 @@ struct LogFileInfo {
     /// Opened file handle to the rollout file.
     file: File,
 
+    /// Location of the rollout file.
+    path: PathBuf,
+
     /// Session ID (also embedded in filename).
     session_id: Uuid,
 
@@ -253,6 +435,83 @@ struct LogFileInfo {
     timestamp: OffsetDateTime,
 }
 
+/// Recursively collect every `rollout-*.jsonl` file below `sessions_dir`,
+/// sorted by path. A missing directory yields an empty list.
+pub(crate) fn rollout_files(sessions_dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![sessions_dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                pending.push(path);
+            } else if is_rollout_file_name(&path) {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn is_rollout_file_name(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with("rollout-") && name.ends_with(".jsonl"))
+}
+
+/// Path of the most recently modified rollout, i.e. the session that was
+/// last written to, or `None` if no session has been recorded yet.
+pub fn session_path_for_current(config: &Config) -> std::io::Result<Option<PathBuf>> {
+    let sessions_dir = config.codex_home.join(SESSIONS_SUBDIR);
+    let mut newest: Option<(std::time::SystemTime, PathBuf)> = None;
+    for path in rollout_files(&sessions_dir)? {
+        let modified = fs::metadata(&path)?.modified()?;
+        if newest.as_ref().is_none_or(|(time, _)| modified >= *time) {
+            newest = Some((modified, path));
+        }
+    }
+    Ok(newest.map(|(_, path)| path))
+}
+
+/// Path of the rollout recorded for `session_id`, or `None` if there is none.
+pub fn session_path_for_id(config: &Config, session_id: Uuid) -> std::io::Result<Option<PathBuf>> {
+    let sessions_dir = config.codex_home.join(SESSIONS_SUBDIR);
+    Ok(rollout_files(&sessions_dir)?
+        .into_iter()
+        .find(|path| session_id_from_file_name(path) == Some(session_id)))
+}
+
+/// Session id embedded at the end of a rollout file name by
+/// [`create_log_file`].
+fn session_id_from_file_name(path: &Path) -> Option<Uuid> {
+    let stem = path.file_stem()?.to_str()?;
+    let start = stem.len().checked_sub(36)?;
+    Uuid::parse_str(stem.get(start..)?).ok()
+}
+
+/// Return the full ids of all recorded sessions whose id starts with
+/// `partial` (case-insensitively), sorted, for completing a session id.
+pub fn complete_session_id(config: &Config, partial: &str) -> std::io::Result<Vec<String>> {
+    let partial = partial.to_ascii_lowercase();
+    let sessions_dir = config.codex_home.join(SESSIONS_SUBDIR);
+    let mut ids: Vec<String> = rollout_files(&sessions_dir)?
+        .into_iter()
+        .filter_map(|path| session_id_from_file_name(&path))
+        .map(|id| id.to_string())
+        .filter(|id| id.starts_with(&partial))
+        .collect();
+    ids.sort();
+    ids.dedup();
+    Ok(ids)
+}
+
 fn create_log_file(config: &Config, session_id: Uuid) -> std::io::Result<LogFileInfo> {
     // Resolve ~/.codex/sessions/YYYY/MM/DD and create it if missing.
     let timestamp = OffsetDateTime::now_local()
@@ -282,11 +541,71 @@ fn create_log_file(config: &Config, session_id: Uuid) -> std::io::Result<LogFile
 
     Ok(LogFileInfo {
         file,
+        path,
         session_id,
         timestamp,
     })
 }
 
+fn format_meta_timestamp(timestamp: OffsetDateTime) -> std::io::Result<String> {
+    let timestamp_format: &[FormatItem] =
+        format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3]Z");
+    timestamp
+        .format(timestamp_format)
+        .map_err(|e| IoError::other(format!("failed to format timestamp: {e}")))
+}
+
+/// Copy the rollout of `session_id` into a new session with a fresh id,
+/// leaving the original untouched, and return the new rollout's path.
+///
+/// The copy is filed under today's `YYYY/MM/DD` directory and its metadata
+/// records the original as `parent_session_id`.
+pub fn fork_session(config: &Config, session_id: &str) -> std::io::Result<PathBuf> {
+    let parent_id = Uuid::parse_str(session_id).map_err(|e| {
+        IoError::new(
+            ErrorKind::InvalidInput,
+            format!("invalid session id {session_id}: {e}"),
+        )
+    })?;
+    let source = session_path_for_id(config, parent_id)?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, format!("no session {parent_id}")))?;
+
+    let mut reader = BufReader::new(File::open(&source)?);
+    let mut meta_line = Vec::new();
+    reader.read_until(b'\n', &mut meta_line)?;
+    let mut meta: serde_json::Map<String, Value> =
+        serde_json::from_str(String::from_utf8_lossy(&meta_line).trim_end())
+            .map_err(|e| IoError::other(format!("failed to parse session meta: {e}")))?;
+
+    let LogFileInfo {
+        mut file,
+        path,
+        session_id,
+        timestamp,
+    } = create_log_file(config, Uuid::new_v4())?;
+    let written = format_meta_timestamp(timestamp).and_then(|timestamp| {
+        meta.insert("id".to_string(), Value::String(session_id.to_string()));
+        meta.insert("timestamp".to_string(), Value::String(timestamp));
+        meta.insert(
+            "parent_session_id".to_string(),
+            Value::String(parent_id.to_string()),
+        );
+
+        // Everything after the metadata is copied verbatim.
+        serde_json::to_writer(&mut file, &meta)?;
+        file.write_all(b"\n")?;
+        std::io::copy(&mut reader, &mut file)?;
+        file.flush()
+    });
+    if let Err(e) = written {
+        // Do not leave a half-written rollout behind.
+        drop(file);
+        let _ = fs::remove_file(&path);
+        return Err(e);
+    }
+    Ok(path)
+}
+
 async fn rollout_writer(
     file: tokio::fs::File,
     mut rx: mpsc::Receiver<RolloutCmd>,
@@ -360,3 +679,335 @@ impl JsonlWriter {
         Ok(())
     }
 }
+
+/// Fixtures shared by the tests of modules that work with the sessions
+/// directory.
+#[cfg(test)]
+pub(crate) mod test_support {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use tempfile::TempDir;
+
+    use super::SESSIONS_SUBDIR;
+    use crate::config::Config;
+    use crate::config::ConfigOverrides;
+    use crate::config::ConfigToml;
+
+    pub(crate) fn make_config(codex_home: &TempDir) -> Config {
+        Config::load_from_base_config_with_overrides(
+            ConfigToml::default(),
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )
+        .expect("defaults for test should always succeed")
+    }
+
+    /// Create an empty rollout for session `id` under `sessions/<day>/`.
+    pub(crate) fn touch_session(codex_home: &TempDir, day: &str, id: &str) -> PathBuf {
+        let dir = codex_home.path().join(SESSIONS_SUBDIR).join(day);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("rollout-2025-05-07T17-24-21-{id}.jsonl"));
+        fs::write(&path, "").unwrap();
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_support::make_config;
+    use super::test_support::touch_session;
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+    use tempfile::TempDir;
+
+    const META_LINE: &str = r#"{"id":"5973b6c0-94b8-487b-a530-2aeb6098ae0e","timestamp":"2025-05-07T17:24:21.000Z","instructions":null}"#;
+
+    fn write_rollout(lines: &[&str]) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        for line in lines {
+            writeln!(file, "{line}").unwrap();
+        }
+        file
+    }
+
+    #[test]
+    fn session_records_stream_in_order_and_surface_bad_lines() {
+        let file = write_rollout(&[
+            META_LINE,
+            r#"{"type":"message","role":"user","content":[{"type":"input_text","text":"hi"}]}"#,
+            "",
+            "not json",
+            r#"{"record_type":"state"}"#,
+            r#"{"type":"message","role":"assistant","content":[{"type":"output_text","text":"hello"}]}"#,
+        ]);
+
+        let records: Vec<_> = session_records(file.path()).unwrap().collect();
+
+        assert_eq!(records.len(), 5);
+        assert!(matches!(records[0], Ok(SessionRecord::Meta(_))));
+        assert!(matches!(
+            &records[1],
+            Ok(SessionRecord::Item(ResponseItem::Message { role, .. })) if role == "user"
+        ));
+        let err = records[2].as_ref().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("line 4:"));
+        assert!(matches!(records[3], Ok(SessionRecord::State(_))));
+        assert!(matches!(
+            &records[4],
+            Ok(SessionRecord::Item(ResponseItem::Message { role, .. })) if role == "assistant"
+        ));
+    }
+
+    #[test]
+    fn summarize_session_preserves_turn_order_within_budget() {
+        let long_answer = "word ".repeat(200);
+        let file = write_rollout(&[
+            META_LINE,
+            r#"{"type":"message","role":"user","content":[{"type":"input_text","text":"<environment_context>\n<cwd>/tmp</cwd>\n</environment_context>"}]}"#,
+            r#"{"type":"message","role":"user","content":[{"type":"input_text","text":"Fix   the\nbuild"}]}"#,
+            &format!(
+                r#"{{"type":"message","role":"assistant","content":[{{"type":"output_text","text":"{long_answer}"}}]}}"#
+            ),
+            r#"{"type":"message","role":"user","content":[{"type":"input_text","text":"thanks"}]}"#,
+        ]);
+
+        let summary = summarize_session(file.path(), 120).unwrap();
+
+        assert!(summary.chars().count() <= 120);
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "user: Fix the build");
+        assert!(lines[1].starts_with("assistant: word word"));
+        assert!(lines[1].ends_with('…'));
+        assert_eq!(lines[2], "user: thanks");
+    }
+
+    #[test]
+    fn session_records_accept_crlf_line_endings() {
+        let item =
+            r#"{"type":"message","role":"user","content":[{"type":"input_text","text":"hi"}]}"#;
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{META_LINE}\r\n{item}\r\n\r\n").unwrap();
+
+        let records: Vec<_> = session_records(file.path())
+            .unwrap()
+            .collect::<std::io::Result<_>>()
+            .unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert!(matches!(records[0], SessionRecord::Meta(_)));
+        assert!(matches!(
+            &records[1],
+            SessionRecord::Item(ResponseItem::Message { role, .. }) if role == "user"
+        ));
+    }
+
+    #[test]
+    fn session_records_decode_invalid_utf8_lossily() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "{META_LINE}").unwrap();
+        file.write_all(
+            b"{\"type\":\"message\",\"role\":\"user\",\"content\":[{\"type\":\"input_text\",\"text\":\"caf\xe9\"}]}\n",
+        )
+        .unwrap();
+
+        let records: Vec<_> = session_records(file.path())
+            .unwrap()
+            .collect::<std::io::Result<_>>()
+            .unwrap();
+
+        assert_eq!(records.len(), 2);
+        let SessionRecord::Item(ResponseItem::Message { content, .. }) = &records[1] else {
+            panic!("expected a message, got {:?}", records[1]);
+        };
+        assert_eq!(
+            content,
+            &vec![ContentItem::InputText {
+                text: "caf\u{FFFD}".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn verify_session_reports_clean_rollout() {
+        let file = write_rollout(&[
+            META_LINE,
+            r#"{"type":"message","role":"user","content":[{"type":"input_text","text":"hi"}]}"#,
+            "",
+            r#"{"record_type":"state"}"#,
+        ]);
+
+        let report = verify_session(file.path()).unwrap();
+
+        assert!(report.is_clean());
+        assert_eq!(
+            report,
+            VerifyReport {
+                valid_lines: 3,
+                invalid_lines: 0,
+                first_invalid_line: None,
+            }
+        );
+    }
+
+    #[test]
+    fn verify_session_reports_first_bad_line() {
+        let file = write_rollout(&[
+            META_LINE,
+            r#"{"type":"message","role":"user","content":[{"type":"input_text","text":"hi"}]}"#,
+            r#"{"type":"message","role":"assist"#,
+            META_LINE,
+            r#"{"record_type":"state"}"#,
+        ]);
+
+        let report = verify_session(file.path()).unwrap();
+
+        assert!(!report.is_clean());
+        assert_eq!(
+            report,
+            VerifyReport {
+                valid_lines: 3,
+                invalid_lines: 2,
+                first_invalid_line: Some(3),
+            }
+        );
+    }
+
+    #[test]
+    fn complete_session_id_matches_shared_prefix() {
+        let codex_home = TempDir::new().unwrap();
+        let config = make_config(&codex_home);
+        touch_session(
+            &codex_home,
+            "2025/05/07",
+            "5973b6c0-94b8-487b-a530-2aeb6098ae0e",
+        );
+        touch_session(
+            &codex_home,
+            "2025/05/08",
+            "5973b6c0-0000-487b-a530-2aeb6098ae0e",
+        );
+        touch_session(
+            &codex_home,
+            "2025/05/08",
+            "6a00b6c0-94b8-487b-a530-2aeb6098ae0e",
+        );
+
+        assert_eq!(
+            complete_session_id(&config, "5973B6C0-").unwrap(),
+            vec![
+                "5973b6c0-0000-487b-a530-2aeb6098ae0e".to_string(),
+                "5973b6c0-94b8-487b-a530-2aeb6098ae0e".to_string(),
+            ]
+        );
+        assert_eq!(
+            complete_session_id(&config, "5973b6c0-94").unwrap().len(),
+            1
+        );
+        assert!(complete_session_id(&config, "ffff").unwrap().is_empty());
+    }
+
+    #[test]
+    fn session_path_for_current_returns_newest_rollout() {
+        let codex_home = TempDir::new().unwrap();
+        let config = make_config(&codex_home);
+        assert_eq!(session_path_for_current(&config).unwrap(), None);
+
+        let older = touch_session(
+            &codex_home,
+            "2025/05/08",
+            "6a00b6c0-94b8-487b-a530-2aeb6098ae0e",
+        );
+        let newer = touch_session(
+            &codex_home,
+            "2025/05/07",
+            "5973b6c0-94b8-487b-a530-2aeb6098ae0e",
+        );
+        let an_hour_ago = std::time::SystemTime::now() - std::time::Duration::from_secs(3600);
+        File::options()
+            .write(true)
+            .open(&older)
+            .unwrap()
+            .set_modified(an_hour_ago)
+            .unwrap();
+
+        assert_eq!(session_path_for_current(&config).unwrap(), Some(newer));
+    }
+
+    #[test]
+    fn session_path_for_id_finds_matching_rollout() {
+        let codex_home = TempDir::new().unwrap();
+        let config = make_config(&codex_home);
+        let id = "5973b6c0-94b8-487b-a530-2aeb6098ae0e";
+        let path = touch_session(&codex_home, "2025/05/07", id);
+        touch_session(
+            &codex_home,
+            "2025/05/08",
+            "6a00b6c0-94b8-487b-a530-2aeb6098ae0e",
+        );
+
+        assert_eq!(
+            session_path_for_id(&config, Uuid::parse_str(id).unwrap()).unwrap(),
+            Some(path)
+        );
+        assert_eq!(session_path_for_id(&config, Uuid::new_v4()).unwrap(), None);
+    }
+
+    #[test]
+    fn fork_session_copies_rollout_under_new_id() {
+        let codex_home = TempDir::new().unwrap();
+        let config = make_config(&codex_home);
+        let parent = "5973b6c0-94b8-487b-a530-2aeb6098ae0e";
+        let item =
+            r#"{"type":"message","role":"user","content":[{"type":"input_text","text":"hi"}]}"#;
+        let original = touch_session(&codex_home, "2025/05/07", parent);
+        fs::write(
+            &original,
+            format!(
+                "{}\n{item}\n",
+                r#"{"id":"5973b6c0-94b8-487b-a530-2aeb6098ae0e","timestamp":"2025-05-07T17:24:21.000Z","instructions":null,"git":{"branch":"main"}}"#
+            ),
+        )
+        .unwrap();
+
+        let forked = fork_session(&config, parent).unwrap();
+
+        assert_ne!(forked, original);
+        assert!(forked.starts_with(codex_home.path().join(SESSIONS_SUBDIR)));
+        let text = fs::read_to_string(&forked).unwrap();
+        let mut lines = text.lines();
+        let meta: Value = serde_json::from_str(lines.next().unwrap()).unwrap();
+        let new_id = meta["id"].as_str().unwrap();
+        assert_ne!(new_id, parent);
+        assert_eq!(
+            session_id_from_file_name(&forked).map(|id| id.to_string()),
+            Some(new_id.to_string())
+        );
+        assert_eq!(meta["parent_session_id"], parent);
+        assert_eq!(meta["git"]["branch"], "main");
+        assert_eq!(lines.next(), Some(item));
+        assert_eq!(lines.next(), None);
+        assert!(original.exists());
+    }
+
+    #[test]
+    fn fork_session_accepts_invalid_utf8_metadata() {
+        let codex_home = TempDir::new().unwrap();
+        let config = make_config(&codex_home);
+        let parent = "5973b6c0-94b8-487b-a530-2aeb6098ae0e";
+        let original = touch_session(&codex_home, "2025/05/07", parent);
+        let mut bytes = br#"{"id":"5973b6c0-94b8-487b-a530-2aeb6098ae0e","timestamp":"2025-05-07T17:24:21.000Z","instructions":"caf"#.to_vec();
+        bytes.extend_from_slice(b"\xe9\"}\n");
+        fs::write(&original, bytes).unwrap();
+
+        let forked = fork_session(&config, parent).unwrap();
+
+        let text = fs::read_to_string(&forked).unwrap();
+        let meta: Value = serde_json::from_str(text.lines().next().unwrap()).unwrap();
+        assert_eq!(meta["instructions"], "caf\u{FFFD}");
+        assert_eq!(meta["parent_session_id"], parent);
+    }
+}
@@ -0,0 +1,310 @@
+//! Bundle old session rollouts into a single `.tar.gz` archive so the
+//! `~/.codex/sessions` directory does not grow without bound, and restore them
+//! again later.
+//!
+//! Entries inside the archive keep their `YYYY/MM/DD/rollout-*.jsonl` paths
+//! relative to the sessions directory so they can be restored in place.
+
+use std::fs::File;
+use std::fs::{self};
+use std::io;
+use std::path::Component;
+use std::path::Path;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+
+use crate::config::Config;
+use crate::rollout::SESSIONS_SUBDIR;
+use crate::rollout::rollout_files;
+
+/// Move every rollout file last modified more than `age` ago into a gzipped
+/// tar archive at `out`, returning the number of sessions archived.
+///
+/// The originals are only removed once the archive has been fully written. If
+/// no session is old enough, no archive is created and `0` is returned. An
+/// existing file at `out` is never overwritten; an `AlreadyExists` error is
+/// returned instead so a previous archive cannot be lost.
+pub fn archive_sessions_older_than(
+    config: &Config,
+    age: Duration,
+    out: &Path,
+) -> io::Result<usize> {
+    let sessions_dir = config.codex_home.join(SESSIONS_SUBDIR);
+    let cutoff = SystemTime::now()
+        .checked_sub(age)
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+
+    let mut old = Vec::new();
+    for path in rollout_files(&sessions_dir)? {
+        if fs::metadata(&path)?.modified()? < cutoff {
+            old.push(path);
+        }
+    }
+    if old.is_empty() {
+        return Ok(0);
+    }
+
+    let file = File::options().write(true).create_new(true).open(out)?;
+    let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+    for path in &old {
+        let relative = path.strip_prefix(&sessions_dir).map_err(|e| {
+            io::Error::other(format!(
+                "{} is outside the sessions directory: {e}",
+                path.display()
+            ))
+        })?;
+        builder.append_path_with_name(path, relative)?;
+    }
+    builder.into_inner()?.finish()?.sync_all()?;
+
+    for path in &old {
+        fs::remove_file(path)?;
+    }
+    Ok(old.len())
+}
+
+/// Extract rollouts from an archive created by
+/// [`archive_sessions_older_than`] back into the sessions directory, returning
+/// the number of files restored.
+///
+/// Restored files keep their archived modification time. Files that already
+/// exist locally are left untouched so newer copies are never clobbered. The whole archive is refused, before anything is written,
+/// if any entry would escape the sessions directory.
+pub fn restore_sessions(config: &Config, archive: &Path) -> io::Result<usize> {
+    let sessions_dir = config.codex_home.join(SESSIONS_SUBDIR);
+
+    // Validate every entry first so a malicious archive restores nothing.
+    for entry in open_archive(archive)?.entries()? {
+        let entry = entry?;
+        let path = entry.path()?;
+        if !is_safe_entry_path(&path) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "refusing archive entry outside sessions directory: {}",
+                    path.display()
+                ),
+            ));
+        }
+    }
+
+    let mut restored = 0;
+    for entry in open_archive(archive)?.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let dest = sessions_dir.join(entry.path()?);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = match File::options().write(true).create_new(true).open(&dest) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        };
+        io::copy(&mut entry, &mut file)?;
+        // Keep the archived mtime so restored sessions still look as old as
+        // they are, both to re-archiving and to newest-session lookups.
+        let mtime = Duration::from_secs(entry.header().mtime()?);
+        file.set_modified(SystemTime::UNIX_EPOCH + mtime)?;
+        restored += 1;
+    }
+    Ok(restored)
+}
+
+fn open_archive(archive: &Path) -> io::Result<tar::Archive<GzDecoder<File>>> {
+    Ok(tar::Archive::new(GzDecoder::new(File::open(archive)?)))
+}
+
+/// Only plain relative paths (no `..`, root, or drive prefix) are accepted.
+fn is_safe_entry_path(path: &Path) -> bool {
+    path.components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rollout::test_support::make_config;
+    use crate::rollout::test_support::touch_session;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    /// Create a rollout for session `id` under `sessions/<day>/` whose mtime
+    /// is `age` in the past.
+    fn write_session(codex_home: &TempDir, day: &str, id: &str, age: Duration) -> PathBuf {
+        let path = touch_session(codex_home, day, id);
+        let file = File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() - age).unwrap();
+        path
+    }
+
+    #[test]
+    fn archives_only_sessions_older_than_age() {
+        let codex_home = TempDir::new().unwrap();
+        let config = make_config(&codex_home);
+        let day = Duration::from_secs(24 * 60 * 60);
+        let old = write_session(
+            &codex_home,
+            "2025/01/02",
+            "00000000-0000-0000-0000-00000000000a",
+            day * 30,
+        );
+        let recent = write_session(
+            &codex_home,
+            "2025/03/04",
+            "00000000-0000-0000-0000-00000000000b",
+            day,
+        );
+
+        let out = codex_home.path().join("archive.tar.gz");
+        let archived = archive_sessions_older_than(&config, day * 7, &out).unwrap();
+
+        assert_eq!(archived, 1);
+        assert!(!old.exists());
+        assert!(recent.exists());
+
+        let mut archive = open_archive(&out).unwrap();
+        let names: Vec<PathBuf> = archive
+            .entries()
+            .unwrap()
+            .map(|entry| entry.unwrap().path().unwrap().into_owned())
+            .collect();
+        let sessions_dir = config.codex_home.join(SESSIONS_SUBDIR);
+        assert_eq!(names, vec![old.strip_prefix(&sessions_dir).unwrap()]);
+    }
+
+    #[test]
+    fn archiving_twice_to_same_path_keeps_first_archive() {
+        let codex_home = TempDir::new().unwrap();
+        let config = make_config(&codex_home);
+        let day = Duration::from_secs(24 * 60 * 60);
+        write_session(
+            &codex_home,
+            "2025/01/02",
+            "00000000-0000-0000-0000-00000000000c",
+            day * 30,
+        );
+        let out = codex_home.path().join("archive.tar.gz");
+        assert_eq!(archive_sessions_older_than(&config, day, &out).unwrap(), 1);
+        let first_archive = fs::read(&out).unwrap();
+        let second = write_session(
+            &codex_home,
+            "2025/01/05",
+            "00000000-0000-0000-0000-00000000000d",
+            day * 30,
+        );
+
+        let err = archive_sessions_older_than(&config, day, &out).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert!(second.exists());
+        assert_eq!(fs::read(&out).unwrap(), first_archive);
+    }
+
+    #[test]
+    fn nothing_old_enough_creates_no_archive() {
+        let codex_home = TempDir::new().unwrap();
+        let config = make_config(&codex_home);
+        write_session(
+            &codex_home,
+            "2025/03/04",
+            "00000000-0000-0000-0000-00000000000b",
+            Duration::ZERO,
+        );
+
+        let out = codex_home.path().join("archive.tar.gz");
+        let archived =
+            archive_sessions_older_than(&config, Duration::from_secs(60 * 60), &out).unwrap();
+
+        assert_eq!(archived, 0);
+        assert!(!out.exists());
+    }
+
+    #[test]
+    fn restore_round_trips_archived_sessions() {
+        let codex_home = TempDir::new().unwrap();
+        let config = make_config(&codex_home);
+        let day = Duration::from_secs(24 * 60 * 60);
+        let first = write_session(
+            &codex_home,
+            "2025/01/02",
+            "00000000-0000-0000-0000-00000000000c",
+            day * 30,
+        );
+        let second = write_session(
+            &codex_home,
+            "2025/01/05",
+            "00000000-0000-0000-0000-00000000000d",
+            day * 30,
+        );
+        let out = codex_home.path().join("archive.tar.gz");
+        assert_eq!(archive_sessions_older_than(&config, day, &out).unwrap(), 2);
+
+        let restored = restore_sessions(&config, &out).unwrap();
+
+        assert_eq!(restored, 2);
+        assert!(first.exists());
+        assert!(second.exists());
+        let age = SystemTime::now()
+            .duration_since(fs::metadata(&first).unwrap().modified().unwrap())
+            .unwrap();
+        assert!(age > day * 29, "restored mtime should be preserved");
+    }
+
+    #[test]
+    fn restore_skips_files_that_already_exist() {
+        let codex_home = TempDir::new().unwrap();
+        let config = make_config(&codex_home);
+        let day = Duration::from_secs(24 * 60 * 60);
+        let path = write_session(
+            &codex_home,
+            "2025/01/02",
+            "00000000-0000-0000-0000-00000000000c",
+            day * 30,
+        );
+        let out = codex_home.path().join("archive.tar.gz");
+        archive_sessions_older_than(&config, day, &out).unwrap();
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "newer local copy\n").unwrap();
+
+        let restored = restore_sessions(&config, &out).unwrap();
+
+        assert_eq!(restored, 0);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "newer local copy\n");
+    }
+
+    #[test]
+    fn restore_refuses_path_traversal() {
+        let codex_home = TempDir::new().unwrap();
+        let config = make_config(&codex_home);
+        let out = codex_home.path().join("evil.tar.gz");
+
+        // `tar::Builder` validates paths itself, so write the name bytes
+        // directly into the header to simulate a hostile archive.
+        let data = b"{}\n";
+        let mut header = tar::Header::new_old();
+        let name = b"../escaped.jsonl";
+        header.as_old_mut().name[..name.len()].copy_from_slice(name);
+        header.set_size(data.len() as u64);
+        header.set_entry_type(tar::EntryType::Regular);
+        header.set_mode(0o600);
+        header.set_cksum();
+        let mut builder = tar::Builder::new(GzEncoder::new(
+            File::create(&out).unwrap(),
+            Compression::default(),
+        ));
+        builder.append(&header, &data[..]).unwrap();
+        builder.into_inner().unwrap().finish().unwrap();
+
+        let err = restore_sessions(&config, &out).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(!codex_home.path().join("escaped.jsonl").exists());
+    }
+}
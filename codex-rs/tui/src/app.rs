@@ -5,6 +5,8 @@ use crate::chatwidget::ChatWidget;
 use crate::file_search::FileSearchManager;
 use crate::get_git_diff::get_git_diff;
 use crate::get_login_status;
+use crate::history_cell;
+use crate::history_cell::HistoryCell;
 use crate::onboarding::onboarding_screen::KeyboardHandler;
 use crate::onboarding::onboarding_screen::OnboardingScreen;
 use crate::onboarding::onboarding_screen::OnboardingScreenArgs;
@@ -25,6 +27,8 @@ use ratatui::prelude::Backend;
 use ratatui::text::Line;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::mpsc::Receiver;
@@ -71,6 +75,27 @@ pub(crate) struct App<'a> {
     /// Channel to schedule one-shot animation frames; coalesced by a single
     /// scheduler thread.
     frame_schedule_tx: std::sync::mpsc::Sender<Instant>,
+
+    /// Pauses the input thread while an external program owns the terminal.
+    input_pause: Arc<InputPause>,
+}
+
+/// Shared between the app and its input thread. The thread holds `lock` while
+/// it polls the terminal and stops taking it once `paused` is set, so whoever
+/// sets `paused` and then takes `lock` knows stdin is no longer being read.
+#[derive(Default)]
+struct InputPause {
+    paused: AtomicBool,
+    lock: Mutex<()>,
+}
+
+impl InputPause {
+    fn lock(&self) -> MutexGuard<'_, ()> {
+        match self.lock.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
 }
 
 /// Aggregate parameters needed to create a `ChatWidget`, as creation may be
@@ -97,12 +122,22 @@ impl App<'_> {
 
         let enhanced_keys_supported = supports_keyboard_enhancement().unwrap_or(false);
 
+        let input_pause = Arc::new(InputPause::default());
+
         // Spawn a dedicated thread for reading the crossterm event loop and
         // re-publishing the events as AppEvents, as appropriate.
         {
             let app_event_tx = app_event_tx.clone();
+            let input_pause = input_pause.clone();
             std::thread::spawn(move || {
                 loop {
+                    // Stay off stdin while an external program (e.g. the
+                    // `/rollout` editor) owns the terminal.
+                    if input_pause.paused.load(Ordering::Acquire) {
+                        thread::sleep(Duration::from_millis(50));
+                        continue;
+                    }
+                    let _input_guard = input_pause.lock();
                     // This timeout is necessary to avoid holding the event lock
                     // that crossterm::event::read() acquires. In particular,
                     // reading the cursor position (crossterm::cursor::position())
@@ -227,6 +262,7 @@ impl App<'_> {
             enhanced_keys_supported,
             commit_anim_running: Arc::new(AtomicBool::new(false)),
             frame_schedule_tx: frame_tx,
+            input_pause,
         }
     }
 
@@ -421,6 +457,9 @@ impl App<'_> {
                             widget.add_status_output();
                         }
                     }
+                    SlashCommand::Rollout => {
+                        self.open_rollout_in_editor(terminal)?;
+                    }
                     #[cfg(debug_assertions)]
                     SlashCommand::TestApproval => {
                         use codex_core::protocol::EventMsg;
@@ -518,6 +557,82 @@ impl App<'_> {
         Ok(())
     }
 
+    /// Suspend the TUI and open the current session's rollout in `$EDITOR`,
+    /// restoring the terminal once the editor exits.
+    fn open_rollout_in_editor(&mut self, terminal: &mut tui::Tui) -> Result<()> {
+        // Prefer this process's own session; the newest rollout on disk may
+        // belong to another running instance.
+        let session_id = match &self.app_state {
+            AppState::Chat { widget } => widget.session_id(),
+            AppState::Onboarding { .. } => None,
+        };
+        let found = match session_id {
+            Some(id) => codex_core::rollout::session_path_for_id(&self.config, id),
+            None => codex_core::rollout::session_path_for_current(&self.config),
+        };
+        let path = match found {
+            Ok(Some(path)) => path,
+            Ok(None) => {
+                self.insert_error("No session has been recorded yet.".to_string());
+                return Ok(());
+            }
+            Err(e) => {
+                self.insert_error(format!("Failed to locate the session rollout: {e}"));
+                return Ok(());
+            }
+        };
+        let Some((program, args)) = std::env::var("EDITOR")
+            .ok()
+            .and_then(|editor| shlex::split(&editor))
+            .and_then(|mut words| (!words.is_empty()).then(|| (words.remove(0), words)))
+        else {
+            self.insert_error(format!(
+                "Set $EDITOR to open the session rollout: {}",
+                path.display()
+            ));
+            return Ok(());
+        };
+
+        let input_pause = self.input_pause.clone();
+        input_pause.paused.store(true, Ordering::Release);
+        // Wait for any in-flight poll to finish before handing over stdin.
+        let input_guard = input_pause.lock();
+        tui::restore()?;
+        let status = std::process::Command::new(&program)
+            .args(&args)
+            .arg(&path)
+            .status();
+        *terminal = tui::resume()?;
+        terminal.clear()?;
+        // Discard anything typed while the editor had the terminal, both
+        // still buffered by crossterm and already queued as app events.
+        while crossterm::event::poll(Duration::ZERO)? {
+            crossterm::event::read()?;
+        }
+        let pending: Vec<AppEvent> = self.app_event_rx.try_iter().collect();
+        for event in pending {
+            if !matches!(event, AppEvent::KeyEvent(_) | AppEvent::Paste(_)) {
+                self.app_event_tx.send(event);
+            }
+        }
+        input_pause.paused.store(false, Ordering::Release);
+        drop(input_guard);
+        self.app_event_tx.send(AppEvent::RequestRedraw);
+
+        match status {
+            Ok(status) if status.success() => {}
+            Ok(status) => self.insert_error(format!("`{program}` exited with {status}")),
+            Err(e) => self.insert_error(format!("Failed to launch `{program}`: {e}")),
+        }
+        Ok(())
+    }
+
+    fn insert_error(&self, message: String) {
+        self.app_event_tx.send(AppEvent::InsertHistory(
+            history_cell::new_error_event(message).display_lines(),
+        ));
+    }
+
     pub(crate) fn token_usage(&self) -> codex_core::protocol::TokenUsage {
         match &self.app_state {
             AppState::Chat { widget } => widget.token_usage().clone(),
@@ -28,8 +28,13 @@ use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
 use crate::bottom_pane::textarea::TextArea;
 use crate::bottom_pane::textarea::TextAreaState;
+use crate::history_cell;
+use crate::history_cell::HistoryCell;
+use crate::slash_command::SlashCommand;
 use codex_file_search::FileMatch;
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::str::FromStr;
 
 /// If the pasted content exceeds this number of characters, replace it with a
 /// placeholder in the UI.
@@ -61,6 +66,8 @@ pub(crate) struct ChatComposer {
     token_usage_info: Option<TokenUsageInfo>,
     has_focus: bool,
     placeholder_text: String,
+    disabled_slash_commands: Vec<String>,
+    slash_command_descriptions: HashMap<String, String>,
 }
 
 /// Popup state – at most one can be visible at any time.
@@ -93,6 +100,8 @@ impl ChatComposer {
             token_usage_info: None,
             has_focus: has_input_focus,
             placeholder_text,
+            disabled_slash_commands: Vec::new(),
+            slash_command_descriptions: HashMap::new(),
         }
     }
 
@@ -147,6 +156,16 @@ impl ChatComposer {
         self.history.set_metadata(log_id, entry_count);
     }
 
+    /// Hide the given built-in slash commands from the command popup.
+    pub(crate) fn set_disabled_slash_commands(&mut self, disabled: Vec<String>) {
+        self.disabled_slash_commands = disabled;
+    }
+
+    /// Show these descriptions for built-in slash commands in the popup.
+    pub(crate) fn set_slash_command_descriptions(&mut self, descriptions: HashMap<String, String>) {
+        self.slash_command_descriptions = descriptions;
+    }
+
     /// Integrate an asynchronous response to an on-demand history lookup. If
     /// the entry is present and the offset matches the current cursor we
     /// immediately populate the textarea.
@@ -521,6 +540,14 @@ impl ChatComposer {
                 }
                 self.pending_pastes.clear();
 
+                if let Some(cmd) = self.disabled_command(&text) {
+                    let message = format!("`/{}` is disabled by configuration", cmd.command());
+                    self.app_event_tx.send(AppEvent::InsertHistory(
+                        history_cell::new_error_event(message).display_lines(),
+                    ));
+                    return (InputResult::None, true);
+                }
+
                 if text.is_empty() {
                     (InputResult::None, true)
                 } else {
@@ -532,6 +559,16 @@ impl ChatComposer {
         }
     }
 
+    /// The built-in command `text` invokes exactly (e.g. `/logout`), if it is
+    /// listed in `tui.disabled_slash_commands`. The popup never offers
+    /// disabled commands, so they would otherwise be sent as a prompt.
+    fn disabled_command(&self, text: &str) -> Option<SlashCommand> {
+        let name = text.trim().strip_prefix('/')?;
+        let cmd = SlashCommand::from_str(name).ok()?;
+        cmd.is_disabled(&self.disabled_slash_commands)
+            .then_some(cmd)
+    }
+
     /// Handle generic Input events that modify the textarea content.
     fn handle_input_basic(&mut self, input: KeyEvent) -> (InputResult, bool) {
         // Normal input handling
@@ -561,7 +598,10 @@ impl ChatComposer {
             }
             _ => {
                 if input_starts_with_slash {
-                    let mut command_popup = CommandPopup::new();
+                    let mut command_popup = CommandPopup::new(
+                        &self.disabled_slash_commands,
+                        self.slash_command_descriptions.clone(),
+                    );
                     command_popup.on_composer_text_change(first_line.to_string());
                     self.active_popup = ActivePopup::Command(command_popup);
                 }
@@ -1056,6 +1096,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn disabled_slash_command_is_refused_instead_of_submitted() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let sender = AppEventSender::new(tx);
+        let mut composer =
+            ChatComposer::new(true, sender, false, "Ask Codex to do anything".to_string());
+        composer.set_disabled_slash_commands(vec!["logout".to_string()]);
+
+        for ch in ['/', 'l', 'o', 'g', 'o', 'u', 't'] {
+            let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+
+        let (result, _needs_redraw) =
+            composer.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        if let InputResult::Submitted(text) = result {
+            panic!("disabled command was submitted as a prompt: {text}");
+        }
+        assert!(composer.textarea.is_empty(), "composer should be cleared");
+
+        let events: Vec<AppEvent> = rx.try_iter().collect();
+        assert!(
+            !events
+                .iter()
+                .any(|event| matches!(event, AppEvent::DispatchCommand(_))),
+            "disabled command must not be dispatched"
+        );
+        let error = events
+            .iter()
+            .find_map(|event| match event {
+                AppEvent::InsertHistory(lines) => Some(
+                    lines
+                        .iter()
+                        .flat_map(|line| line.spans.iter())
+                        .map(|span| span.content.as_ref())
+                        .collect::<String>(),
+                ),
+                _ => None,
+            })
+            .expect("expected an error to be inserted into history");
+        assert!(error.contains("`/logout` is disabled by configuration"));
+    }
+
     #[test]
     fn slash_mention_dispatches_command_and_inserts_at() {
         use crossterm::event::KeyCode;
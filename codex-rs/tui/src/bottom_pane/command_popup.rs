@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::widgets::WidgetRef;
@@ -13,14 +15,16 @@ use codex_common::fuzzy_match::fuzzy_match;
 pub(crate) struct CommandPopup {
     command_filter: String,
     all_commands: Vec<(&'static str, SlashCommand)>,
+    descriptions: HashMap<String, String>,
     state: ScrollState,
 }
 
 impl CommandPopup {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(disabled_commands: &[String], descriptions: HashMap<String, String>) -> Self {
         Self {
             command_filter: String::new(),
-            all_commands: built_in_slash_commands(),
+            all_commands: built_in_slash_commands(disabled_commands),
+            descriptions,
             state: ScrollState::new(),
         }
     }
@@ -124,7 +128,7 @@ impl WidgetRef for CommandPopup {
                     name: format!("/{}", cmd.command()),
                     match_indices: indices.map(|v| v.into_iter().map(|i| i + 1).collect()),
                     is_current: false,
-                    description: Some(cmd.description().to_string()),
+                    description: Some(cmd.description_with(&self.descriptions).to_string()),
                 })
                 .collect()
         };
@@ -138,7 +142,7 @@ mod tests {
 
     #[test]
     fn filter_includes_init_when_typing_prefix() {
-        let mut popup = CommandPopup::new();
+        let mut popup = CommandPopup::new(&[], HashMap::new());
         // Simulate the composer line starting with '/in' so the popup filters
         // matching commands by prefix.
         popup.on_composer_text_change("/in".to_string());
@@ -154,7 +158,7 @@ mod tests {
 
     #[test]
     fn selecting_init_by_exact_match() {
-        let mut popup = CommandPopup::new();
+        let mut popup = CommandPopup::new(&[], HashMap::new());
         popup.on_composer_text_change("/init".to_string());
 
         // When an exact match exists, the selected command should be that
@@ -165,4 +169,18 @@ mod tests {
             None => panic!("expected a selected command for exact match"),
         }
     }
+
+    #[test]
+    fn disabled_command_is_not_offered() {
+        let mut popup = CommandPopup::new(&["init".to_string()], HashMap::new());
+        popup.on_composer_text_change("/init".to_string());
+
+        assert!(
+            !popup
+                .filtered_commands()
+                .iter()
+                .any(|cmd| cmd.command() == "init"),
+            "expected disabled '/init' to be hidden"
+        );
+    }
 }
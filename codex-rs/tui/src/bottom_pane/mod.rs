@@ -10,6 +10,7 @@ use crossterm::event::KeyEvent;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::widgets::WidgetRef;
+use std::collections::HashMap;
 
 mod approval_modal_view;
 mod bottom_pane_view;
@@ -278,6 +279,14 @@ impl BottomPane<'_> {
         self.composer.set_history_metadata(log_id, entry_count);
     }
 
+    pub(crate) fn set_disabled_slash_commands(&mut self, disabled: Vec<String>) {
+        self.composer.set_disabled_slash_commands(disabled);
+    }
+
+    pub(crate) fn set_slash_command_descriptions(&mut self, descriptions: HashMap<String, String>) {
+        self.composer.set_slash_command_descriptions(descriptions);
+    }
+
     pub(crate) fn on_history_entry_response(
         &mut self,
         log_id: u64,
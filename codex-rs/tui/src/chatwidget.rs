@@ -498,16 +498,19 @@ impl ChatWidget<'_> {
         let mut rng = rand::rng();
         let placeholder = EXAMPLE_PROMPTS[rng.random_range(0..EXAMPLE_PROMPTS.len())].to_string();
         let codex_op_tx = spawn_agent(config.clone(), app_event_tx.clone(), conversation_manager);
+        let mut bottom_pane = BottomPane::new(BottomPaneParams {
+            app_event_tx: app_event_tx.clone(),
+            has_input_focus: true,
+            enhanced_keys_supported,
+            placeholder_text: placeholder,
+        });
+        bottom_pane.set_disabled_slash_commands(config.tui.disabled_slash_commands.clone());
+        bottom_pane.set_slash_command_descriptions(config.tui.slash_command_descriptions.clone());
 
         Self {
-            app_event_tx: app_event_tx.clone(),
+            app_event_tx,
             codex_op_tx,
-            bottom_pane: BottomPane::new(BottomPaneParams {
-                app_event_tx,
-                has_input_focus: true,
-                enhanced_keys_supported,
-                placeholder_text: placeholder,
-            }),
+            bottom_pane,
             active_exec_cell: None,
             config: config.clone(),
             initial_user_message: create_initial_user_message(
@@ -736,6 +739,11 @@ impl ChatWidget<'_> {
         self.submit_user_message(text.into());
     }
 
+    /// Id of the current session, once Codex has configured it.
+    pub(crate) fn session_id(&self) -> Option<Uuid> {
+        self.session_id
+    }
+
     pub(crate) fn token_usage(&self) -> &TokenUsage {
         &self.total_token_usage
     }
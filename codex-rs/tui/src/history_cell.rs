@@ -154,7 +154,28 @@ pub(crate) fn new_session_info(
             None => config.cwd.display().to_string(),
         };
 
-        let lines: Vec<Line<'static>> = vec![
+        let descriptions = &config.tui.slash_command_descriptions;
+        let suggested: Vec<Line<'static>> =
+            [SlashCommand::Init, SlashCommand::Status, SlashCommand::Diff]
+                .into_iter()
+                .filter(|cmd| !cmd.is_disabled(&config.tui.disabled_slash_commands))
+                .map(|cmd| {
+                    Line::from(
+                        format!(
+                            " /{} - {}",
+                            cmd.command(),
+                            cmd.description_with(descriptions)
+                        )
+                        .dim(),
+                    )
+                })
+                .collect();
+        let get_started = if suggested.is_empty() {
+            " To get started, describe a task."
+        } else {
+            " To get started, describe a task or try one of these commands:"
+        };
+        let mut lines: Vec<Line<'static>> = vec![
             Line::from(vec![
                 Span::raw(">_ ").dim(),
                 Span::styled(
@@ -164,13 +185,13 @@ pub(crate) fn new_session_info(
                 Span::raw(format!(" {cwd_str}")).dim(),
             ]),
             Line::from("".dim()),
-            Line::from(" To get started, describe a task or try one of these commands:".dim()),
-            Line::from("".dim()),
-            Line::from(format!(" /init - {}", SlashCommand::Init.description()).dim()),
-            Line::from(format!(" /status - {}", SlashCommand::Status.description()).dim()),
-            Line::from(format!(" /diff - {}", SlashCommand::Diff.description()).dim()),
+            Line::from(get_started.dim()),
             Line::from("".dim()),
         ];
+        if !suggested.is_empty() {
+            lines.extend(suggested);
+            lines.push(Line::from("".dim()));
+        }
         PlainHistoryCell { lines }
     } else if config.model == model {
         PlainHistoryCell { lines: Vec::new() }
@@ -926,6 +947,40 @@ fn format_mcp_invocation<'a>(invocation: McpInvocation) -> Line<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use codex_core::config::ConfigOverrides;
+    use codex_core::config::ConfigToml;
+
+    #[test]
+    fn session_info_omits_disabled_suggestions() {
+        let mut config = Config::load_from_base_config_with_overrides(
+            ConfigToml::default(),
+            ConfigOverrides::default(),
+            std::env::temp_dir(),
+        )
+        .expect("load default config");
+        config.tui.disabled_slash_commands = vec!["init".to_string(), "diff".to_string()];
+        let event = SessionConfiguredEvent {
+            session_id: Uuid::new_v4(),
+            model: config.model.clone(),
+            history_log_id: 0,
+            history_entry_count: 0,
+        };
+
+        let text: Vec<String> = new_session_info(&config, event, true)
+            .display_lines()
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect();
+
+        assert!(text.iter().any(|line| line.starts_with(" /status")));
+        assert!(!text.iter().any(|line| line.starts_with(" /init")));
+        assert!(!text.iter().any(|line| line.starts_with(" /diff")));
+    }
 
     #[test]
     fn parsed_command_with_newlines_starts_each_line_at_origin() {
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use strum::IntoEnumIterator;
 use strum_macros::AsRefStr;
 use strum_macros::EnumIter;
@@ -18,6 +20,7 @@ pub enum SlashCommand {
     Diff,
     Mention,
     Status,
+    Rollout,
     Logout,
     Quit,
     #[cfg(debug_assertions)]
@@ -35,20 +38,79 @@ impl SlashCommand {
             SlashCommand::Diff => "show git diff (including untracked files)",
             SlashCommand::Mention => "mention a file",
             SlashCommand::Status => "show current session configuration and token usage",
+            SlashCommand::Rollout => "open the current session's rollout file in $EDITOR",
             SlashCommand::Logout => "log out of Codex",
             #[cfg(debug_assertions)]
             SlashCommand::TestApproval => "test approval request",
         }
     }
 
+    /// Description from `overrides` (configured via
+    /// `tui.slash_command_descriptions`), falling back to
+    /// [`SlashCommand::description`] when the command has no entry.
+    pub fn description_with(self, overrides: &HashMap<String, String>) -> &str {
+        overrides
+            .get(self.command())
+            .map_or(self.description(), String::as_str)
+    }
+
     /// Command string without the leading '/'. Provided for compatibility with
     /// existing code that expects a method named `command()`.
     pub fn command(self) -> &'static str {
         self.into()
     }
+
+    /// Whether this command appears in `disabled`, the list configured via
+    /// `tui.disabled_slash_commands`.
+    pub fn is_disabled(self, disabled: &[String]) -> bool {
+        disabled.iter().any(|name| name == self.command())
+    }
 }
 
-/// Return all built-in commands in a Vec paired with their command string.
-pub fn built_in_slash_commands() -> Vec<(&'static str, SlashCommand)> {
-    SlashCommand::iter().map(|c| (c.command(), c)).collect()
+/// Return all built-in commands that are not `disabled` in a Vec paired with
+/// their command string.
+pub fn built_in_slash_commands(disabled: &[String]) -> Vec<(&'static str, SlashCommand)> {
+    SlashCommand::iter()
+        .filter(|c| !c.is_disabled(disabled))
+        .map(|c| (c.command(), c))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_commands_are_excluded_from_listing() {
+        let disabled = vec!["logout".to_string()];
+
+        let commands = built_in_slash_commands(&disabled);
+
+        assert!(!commands.iter().any(|(_, c)| *c == SlashCommand::Logout));
+        assert!(commands.iter().any(|(_, c)| *c == SlashCommand::Status));
+        assert_eq!(
+            built_in_slash_commands(&[]).len(),
+            SlashCommand::iter().count()
+        );
+    }
+
+    #[test]
+    fn description_override_replaces_default() {
+        let overrides = HashMap::from([("diff".to_string(), "afficher le diff".to_string())]);
+
+        assert_eq!(
+            SlashCommand::Diff.description_with(&overrides),
+            "afficher le diff"
+        );
+    }
+
+    #[test]
+    fn missing_description_override_falls_back() {
+        let overrides = HashMap::from([("diff".to_string(), "afficher le diff".to_string())]);
+
+        assert_eq!(
+            SlashCommand::Status.description_with(&overrides),
+            SlashCommand::Status.description()
+        );
+    }
 }
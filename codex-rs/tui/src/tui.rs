@@ -23,6 +23,14 @@ pub type Tui = Terminal<CrosstermBackend<Stdout>>;
 
 /// Initialize the terminal (inline viewport; history stays in normal scrollback)
 pub fn init(_config: &Config) -> Result<Tui> {
+    let tui = resume()?;
+    set_panic_hook();
+    Ok(tui)
+}
+
+/// Re-enter the terminal modes after [`restore`], e.g. once an external
+/// program has exited, without installing the panic hook again.
+pub fn resume() -> Result<Tui> {
     execute!(stdout(), EnableBracketedPaste)?;
 
     enable_raw_mode()?;
@@ -40,7 +48,6 @@ pub fn init(_config: &Config) -> Result<Tui> {
                 | KeyboardEnhancementFlags::REPORT_ALTERNATE_KEYS
         )
     );
-    set_panic_hook();
 
     // Clear screen and move cursor to top-left before drawing UI
     execute!(stdout(), Clear(ClearType::All), MoveTo(0, 0))?;